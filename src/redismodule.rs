@@ -25,8 +25,12 @@ pub enum RedisValue {
     SimpleString(String),
     BulkString(String),
     Integer(i64),
+    Double(f64),
+    Bool(bool),
     Array(Vec<RedisValue>),
+    Map(Vec<(RedisValue, RedisValue)>),
     None,
+    Null,
 }
 
 pub const REDIS_OK: RedisResult = Ok(RedisValue::SimpleStringStatic("OK"));
@@ -61,6 +65,29 @@ impl From<Vec<String>> for RedisValue {
     }
 }
 
+impl From<f64> for RedisValue {
+    fn from(f: f64) -> Self {
+        RedisValue::Double(f)
+    }
+}
+
+impl From<bool> for RedisValue {
+    fn from(b: bool) -> Self {
+        RedisValue::Bool(b)
+    }
+}
+
+impl From<Vec<(String, RedisValue)>> for RedisValue {
+    fn from(pairs: Vec<(String, RedisValue)>) -> Self {
+        RedisValue::Map(
+            pairs
+                .into_iter()
+                .map(|(key, value)| (RedisValue::BulkString(key), value))
+                .collect(),
+        )
+    }
+}
+
 impl From<Vec<i64>> for RedisValue {
     fn from(nums: Vec<i64>) -> Self {
         RedisValue::Array(nums.into_iter().map(RedisValue::Integer).collect())
@@ -78,6 +105,27 @@ impl From<usize> for RedisValue {
 pub trait NextArg: Iterator {
     fn next_string(&mut self) -> Result<String, RedisError>;
     fn next_i64(&mut self) -> Result<i64, RedisError>;
+    fn next_u64(&mut self) -> Result<u64, RedisError>;
+    fn next_f64(&mut self) -> Result<f64, RedisError>;
+
+    // Only supported when iterating over `RedisString` arguments, which carry enough
+    // information (a `RedisModuleString` pointer) to hand back without a fresh allocation
+    fn next_arg(&mut self) -> Result<RedisString, RedisError> {
+        Err(RedisError::Str("next_arg requires an iterator over RedisString arguments"))
+    }
+
+    // See `next_arg` above regarding which iterators can support this
+    fn next_str<'a>(&'a mut self) -> Result<&'a str, RedisError> {
+        Err(RedisError::Str("next_str requires an iterator over RedisString arguments"))
+    }
+
+    // Ensures no arguments are left unconsumed, for commands that want strict arity checking
+    fn done(&mut self) -> Result<(), RedisError> {
+        match self.next() {
+            Some(_) => Err(RedisError::WrongArity),
+            None => Ok(()),
+        }
+    }
 }
 
 impl<T: Iterator<Item = String>> NextArg for T {
@@ -89,6 +137,66 @@ impl<T: Iterator<Item = String>> NextArg for T {
         self.next()
             .map_or(Err(RedisError::WrongArity), parse_integer)
     }
+
+    fn next_u64(&mut self) -> Result<u64, RedisError> {
+        self.next()
+            .map_or(Err(RedisError::WrongArity), parse_unsigned)
+    }
+
+    fn next_f64(&mut self) -> Result<f64, RedisError> {
+        self.next()
+            .map_or(Err(RedisError::WrongArity), parse_float)
+    }
+}
+
+impl<T: Iterator<Item = RedisString>> NextArg for T {
+    fn next_string(&mut self) -> Result<String, RedisError> {
+        self.next().map_or(Err(RedisError::WrongArity), |arg| {
+            RedisString::from_ptr(arg.inner)
+                .map(str::to_string)
+                .map_err(|_| RedisError::Str("Couldn't parse as a UTF-8 string"))
+        })
+    }
+
+    fn next_i64(&mut self) -> Result<i64, RedisError> {
+        self.next().map_or(Err(RedisError::WrongArity), |arg| {
+            RedisString::from_ptr(arg.inner)
+                .map_err(|_| RedisError::Str("Couldn't parse as a UTF-8 string"))
+                .and_then(|s| parse_integer(s.to_string()))
+        })
+    }
+
+    fn next_u64(&mut self) -> Result<u64, RedisError> {
+        self.next().map_or(Err(RedisError::WrongArity), |arg| {
+            RedisString::from_ptr(arg.inner)
+                .map_err(|_| RedisError::Str("Couldn't parse as a UTF-8 string"))
+                .and_then(|s| parse_unsigned(s.to_string()))
+        })
+    }
+
+    fn next_f64(&mut self) -> Result<f64, RedisError> {
+        self.next().map_or(Err(RedisError::WrongArity), |arg| {
+            RedisString::from_ptr(arg.inner)
+                .map_err(|_| RedisError::Str("Couldn't parse as a UTF-8 string"))
+                .and_then(|s| parse_float(s.to_string()))
+        })
+    }
+
+    fn next_arg(&mut self) -> Result<RedisString, RedisError> {
+        self.next().map_or(Err(RedisError::WrongArity), Result::Ok)
+    }
+
+    // Every `RedisString` this crate hands out (see e.g. `ZSetScoreIterator::next`, which wraps
+    // each element via `RedisModule_ZsetRangeCurrentElement` + `from_redis_module_string`) owns
+    // a reference the caller is expected to free, independent of whatever other reference(s) the
+    // engine itself may be holding to the same underlying value. An `Iterator<Item = RedisString>`
+    // over command arguments is expected to uphold the same contract (e.g. by taking its own
+    // reference via `RedisModule_HoldString` when built from argv), so `arg` going out of scope
+    // here releases only the reference this iterator took for us, not the engine's own copy
+    fn next_str<'a>(&'a mut self) -> Result<&'a str, RedisError> {
+        let arg = self.next().ok_or(RedisError::WrongArity)?;
+        RedisString::from_ptr(arg.inner).map_err(|_| RedisError::Str("Couldn't parse as a UTF-8 string"))
+    }
 }
 
 pub fn parse_integer(arg: String) -> Result<i64, RedisError> {
@@ -96,6 +204,57 @@ pub fn parse_integer(arg: String) -> Result<i64, RedisError> {
         .map_err(|_| RedisError::String(format!("Couldn't parse as integer: {}", arg)))
 }
 
+pub fn parse_unsigned(arg: String) -> Result<u64, RedisError> {
+    arg.parse::<u64>()
+        .map_err(|_| RedisError::String(format!("Couldn't parse as unsigned integer: {}", arg)))
+}
+
+pub fn parse_float(arg: String) -> Result<f64, RedisError> {
+    arg.parse::<f64>()
+        .map_err(|_| RedisError::String(format!("Couldn't parse as double: {}", arg)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_unsigned_rejects_negative_and_overflow() {
+        assert!(parse_unsigned("-1".to_string()).is_err());
+        assert!(parse_unsigned("18446744073709551616".to_string()).is_err());
+        assert!(parse_unsigned("not_a_number".to_string()).is_err());
+        assert_eq!(parse_unsigned("42".to_string()).unwrap(), 42);
+    }
+
+    #[test]
+    fn parse_float_parses_and_rejects_garbage() {
+        assert!(parse_float("not_a_number".to_string()).is_err());
+        assert_eq!(parse_float("3.5".to_string()).unwrap(), 3.5);
+    }
+
+    #[test]
+    fn next_u64_rejects_negative_and_overflow() {
+        assert!(vec!["-1".to_string()].into_iter().next_u64().is_err());
+        assert!(vec!["18446744073709551616".to_string()]
+            .into_iter()
+            .next_u64()
+            .is_err());
+        assert_eq!(vec!["7".to_string()].into_iter().next_u64().unwrap(), 7);
+    }
+
+    #[test]
+    fn next_f64_parses_and_rejects_garbage() {
+        assert!(vec!["garbage".to_string()].into_iter().next_f64().is_err());
+        assert_eq!(vec!["1.25".to_string()].into_iter().next_f64().unwrap(), 1.25);
+    }
+
+    #[test]
+    fn done_errors_on_leftover_args() {
+        assert!(vec!["extra".to_string()].into_iter().done().is_err());
+        assert!(std::iter::empty::<String>().done().is_ok());
+    }
+}
+
 ///////////////////////////////////////////////////
 
 #[derive(Debug)]
@@ -112,12 +271,65 @@ impl RedisString {
         RedisString { ctx, inner }
     }
 
+    // Like `create`, but for binary-safe content: `RedisModule_CreateString` takes an explicit
+    // length, so unlike `create` this doesn't go through `CString` and doesn't reject embedded NULs
+    pub fn create_from_slice(ctx: *mut raw::RedisModuleCtx, bytes: &[u8]) -> RedisString {
+        let inner = unsafe {
+            raw::RedisModule_CreateString.unwrap()(ctx, bytes.as_ptr() as *const libc::c_char, bytes.len())
+        };
+
+        RedisString { ctx, inner }
+    }
+
     pub fn from_ptr<'a>(ptr: *mut raw::RedisModuleString) -> Result<&'a str, str::Utf8Error> {
         let mut len: libc::size_t = 0;
         let bytes = unsafe { raw::RedisModule_StringPtrLen.unwrap()(ptr, &mut len) };
 
         str::from_utf8(unsafe { slice::from_raw_parts(bytes as *const u8, len) })
     }
+
+    pub fn as_slice(&self) -> &[u8] {
+        let mut len: libc::size_t = 0;
+        let bytes = unsafe { raw::RedisModule_StringPtrLen.unwrap()(self.inner, &mut len) };
+
+        unsafe { slice::from_raw_parts(bytes as *const u8, len) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn try_as_str(&self) -> Result<&str, str::Utf8Error> {
+        str::from_utf8(self.as_slice())
+    }
+
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf8_lossy(self.as_slice()).into_owned()
+    }
+}
+
+impl Clone for RedisString {
+    fn clone(&self) -> Self {
+        let inner = unsafe { raw::RedisModule_HoldString.unwrap()(self.ctx, self.inner) };
+
+        RedisString { ctx: self.ctx, inner }
+    }
+}
+
+impl PartialEq for RedisString {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl PartialOrd for RedisString {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
 }
 
 impl Drop for RedisString {
@@ -1,4 +1,4 @@
-use std::{ffi::c_int, ops::{Bound, RangeBounds}, ptr};
+use std::{ffi::c_int, mem, ops::{Bound, RangeBounds}, ptr};
 
 use bitflags::bitflags;
 
@@ -26,7 +26,7 @@ pub enum ZAddResult {
     Nop,
 }
 
-// Performs `ZRANGE BYSCORE` on range bounds. Unbounded range is unsupported
+// Performs `ZRANGE BYSCORE` on range bounds
 pub struct ZSetScoreIterator<'a> {
     key: &'a RedisKey,
 }
@@ -37,8 +37,8 @@ impl<'a> ZSetScoreIterator<'a> {
            return Err(RedisError::WrongType);
         }
 
-        let (min, minex) = extract_bound(range.start_bound())?;
-        let (max, maxex) = extract_bound(range.end_bound())?;
+        let (min, minex) = extract_bound(range.start_bound(), f64::NEG_INFINITY);
+        let (max, maxex) = extract_bound(range.end_bound(), f64::INFINITY);
 
         let status: Status = unsafe {
             let init = match last {
@@ -52,45 +52,215 @@ impl<'a> ZSetScoreIterator<'a> {
             Status::Err => redis_error!("failed to create ZSet iterator"),
         }
     }
+
+    // Turns this iterator into one that also yields each element's score, sparing callers
+    // a separate `ZSCORE` lookup
+    pub fn scored(self) -> ZSetScoredIterator<'a> {
+        let key = self.key;
+        mem::forget(self);
+        ZSetScoredIterator { key }
+    }
 }
 
 impl<'a> Iterator for ZSetScoreIterator<'a> {
     type Item = RedisString;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if unsafe { RedisModule_ZsetRangeEndReached.unwrap()(self.key.key_inner) } == 1 {
-            return None;
-        }
-        let item_ptr = unsafe { RedisModule_ZsetRangeCurrentElement.unwrap()(self.key.key_inner, ptr::null_mut()) };
-        let item = RedisString::from_redis_module_string(self.key.ctx, item_ptr);
-        unsafe { RedisModule_ZsetRangeNext.unwrap()(self.key.key_inner) };
-        Some(item)
+        zset_range_step(self.key, ptr::null_mut(), || unsafe {
+            RedisModule_ZsetRangeNext.unwrap()(self.key.key_inner)
+        })
     }
 }
 
 impl<'a> DoubleEndedIterator for ZSetScoreIterator<'a> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if unsafe { RedisModule_ZsetRangeEndReached.unwrap()(self.key.key_inner) } == 1 {
-            return None;
-        }
-        let item_ptr = unsafe { RedisModule_ZsetRangeCurrentElement.unwrap()(self.key.key_inner, ptr::null_mut()) };
-        let item = RedisString::from_redis_module_string(self.key.ctx, item_ptr);
-        unsafe { RedisModule_ZsetRangePrev.unwrap()(self.key.key_inner) };
-        Some(item)
+        zset_range_step(self.key, ptr::null_mut(), || unsafe {
+            RedisModule_ZsetRangePrev.unwrap()(self.key.key_inner)
+        })
     }
 }
 
 impl<'a> Drop for ZSetScoreIterator<'a> {
     fn drop(&mut self) {
-       unsafe { RedisModule_ZsetRangeStop.unwrap()(self.key.key_inner) }
+        zset_range_stop(self.key)
+    }
+}
+
+// Like `ZSetScoreIterator`, but yields `(RedisString, f64)` pairs instead of discarding the score
+pub struct ZSetScoredIterator<'a> {
+    key: &'a RedisKey,
+}
+
+impl<'a> Iterator for ZSetScoredIterator<'a> {
+    type Item = (RedisString, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut score: f64 = 0.0;
+        let item = zset_range_step(self.key, &mut score, || unsafe {
+            RedisModule_ZsetRangeNext.unwrap()(self.key.key_inner)
+        })?;
+        Some((item, score))
+    }
+}
+
+impl<'a> DoubleEndedIterator for ZSetScoredIterator<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let mut score: f64 = 0.0;
+        let item = zset_range_step(self.key, &mut score, || unsafe {
+            RedisModule_ZsetRangePrev.unwrap()(self.key.key_inner)
+        })?;
+        Some((item, score))
+    }
+}
+
+impl<'a> Drop for ZSetScoredIterator<'a> {
+    fn drop(&mut self) {
+        zset_range_stop(self.key)
+    }
+}
+
+// Shared stepping logic behind every ZSet range iterator in this module: bail out once the underlying
+// range cursor is exhausted, otherwise read the current element (and its score into `score`,
+// if non-null) before calling `advance` to move the cursor in the desired direction
+fn zset_range_step(key: &RedisKey, score: *mut f64, advance: impl FnOnce()) -> Option<RedisString> {
+    if unsafe { RedisModule_ZsetRangeEndReached.unwrap()(key.key_inner) } == 1 {
+        return None;
+    }
+    let item_ptr = unsafe { RedisModule_ZsetRangeCurrentElement.unwrap()(key.key_inner, score) };
+    let item = RedisString::from_redis_module_string(key.ctx, item_ptr);
+    advance();
+    Some(item)
+}
+
+fn zset_range_stop(key: &RedisKey) {
+    unsafe { RedisModule_ZsetRangeStop.unwrap()(key.key_inner) }
+}
+
+// Returned bool indicates if bound is excluded. An unbounded side maps to `unbounded`
+// (`f64::NEG_INFINITY`/`f64::INFINITY`), which `RedisModule_Zset{First,Last}InScoreRange` accept directly
+fn extract_bound(bound: Bound<&f64>, unbounded: f64) -> (f64, bool) {
+    match bound {
+        Bound::Included(value) => (*value, false),
+        Bound::Excluded(value) => (*value, true),
+        Bound::Unbounded => (unbounded, false),
+    }
+}
+
+// Performs `ZRANGE BYLEX` on range bounds
+pub struct ZSetLexIterator<'a> {
+    key: &'a RedisKey,
+}
+
+impl<'a> ZSetLexIterator<'a> {
+    pub(super) fn new(key: &'a RedisKey, range: impl RangeBounds<RedisString>, last: bool) -> RedisResult<Self> {
+        if key.key_type() != KeyType::ZSet {
+           return Err(RedisError::WrongType);
+        }
+
+        let min = extract_lex_bound(key.ctx, range.start_bound(), b"-");
+        let max = extract_lex_bound(key.ctx, range.end_bound(), b"+");
+
+        let status: Status = unsafe {
+            let init = match last {
+                true => RedisModule_ZsetLastInLexRange.unwrap(),
+                false => RedisModule_ZsetFirstInLexRange.unwrap(),
+            };
+            init(key.key_inner, min.inner, max.inner).into()
+        };
+        match status {
+            Status::Ok => Ok(Self{ key }),
+            Status::Err => redis_error!("failed to create ZSet iterator"),
+        }
+    }
+}
+
+impl<'a> Iterator for ZSetLexIterator<'a> {
+    type Item = RedisString;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        zset_range_step(self.key, ptr::null_mut(), || unsafe {
+            RedisModule_ZsetRangeNext.unwrap()(self.key.key_inner)
+        })
+    }
+}
+
+impl<'a> DoubleEndedIterator for ZSetLexIterator<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        zset_range_step(self.key, ptr::null_mut(), || unsafe {
+            RedisModule_ZsetRangePrev.unwrap()(self.key.key_inner)
+        })
+    }
+}
+
+impl<'a> Drop for ZSetLexIterator<'a> {
+    fn drop(&mut self) {
+        zset_range_stop(self.key)
     }
 }
 
-// Returned bool indicates if bound is excluded
-fn extract_bound(bound: Bound<&f64>) -> RedisResult<(f64, bool)> {
+// Translates a lex-range bound into a `[`/`(`-prefixed RedisString, or the `-`/`+` sentinel
+// for an unbounded start/end respectively, per Redis's `ZRANGEBYLEX` conventions. ZSet members
+// are binary-safe, so this builds the prefixed value from raw bytes rather than a `&str` --
+// going through UTF-8 would both reject valid binary members and, via `RedisString::create`'s
+// `CString`, panic on a member containing an embedded NUL
+fn extract_lex_bound(ctx: *mut RedisModuleCtx, bound: Bound<&RedisString>, unbounded: &[u8]) -> RedisString {
+    let bytes_bound = match bound {
+        Bound::Included(value) => Bound::Included(value.as_slice()),
+        Bound::Excluded(value) => Bound::Excluded(value.as_slice()),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+    RedisString::create_from_slice(ctx, &lex_bound_bytes(bytes_bound, unbounded))
+}
+
+// Pure byte-buffer half of `extract_lex_bound`, split out so it can be unit tested without a
+// live Redis context
+fn lex_bound_bytes(bound: Bound<&[u8]>, unbounded: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::new();
     match bound {
-        Bound::Included(value) => Ok((*value, false)),
-        Bound::Excluded(value) => Ok((*value, true)),
-        Bound::Unbounded => redis_error!("unbounded range is unsupported"),
+        Bound::Included(value) => {
+            buf.push(b'[');
+            buf.extend_from_slice(value);
+        }
+        Bound::Excluded(value) => {
+            buf.push(b'(');
+            buf.extend_from_slice(value);
+        }
+        Bound::Unbounded => buf.extend_from_slice(unbounded),
+    }
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_bound_maps_unbounded_to_infinity() {
+        assert_eq!(extract_bound(Bound::Unbounded, f64::NEG_INFINITY), (f64::NEG_INFINITY, false));
+        assert_eq!(extract_bound(Bound::Unbounded, f64::INFINITY), (f64::INFINITY, false));
+    }
+
+    #[test]
+    fn extract_bound_preserves_inclusivity() {
+        assert_eq!(extract_bound(Bound::Included(&1.0), f64::NEG_INFINITY), (1.0, false));
+        assert_eq!(extract_bound(Bound::Excluded(&1.0), f64::NEG_INFINITY), (1.0, true));
+    }
+
+    #[test]
+    fn lex_bound_bytes_prefixes_by_inclusivity() {
+        assert_eq!(lex_bound_bytes(Bound::Included(b"abc".as_slice()), b"-"), b"[abc".to_vec());
+        assert_eq!(lex_bound_bytes(Bound::Excluded(b"abc".as_slice()), b"+"), b"(abc".to_vec());
+    }
+
+    #[test]
+    fn lex_bound_bytes_maps_unbounded_to_sentinel() {
+        assert_eq!(lex_bound_bytes(Bound::Unbounded, b"-"), b"-".to_vec());
+        assert_eq!(lex_bound_bytes(Bound::Unbounded, b"+"), b"+".to_vec());
+    }
+
+    #[test]
+    fn lex_bound_bytes_is_binary_safe() {
+        let member: &[u8] = b"a\0b";
+        assert_eq!(lex_bound_bytes(Bound::Included(member), b"-"), b"[a\0b".to_vec());
     }
 }